@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use actix_web::{web, Responder};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use sysinfo::System;
+use tokio::net::UdpSocket;
+use tokio::sync::RwLock;
+use tokio::time::{sleep, Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::models::{CpuInfo, SystemInfo};
+use crate::state::AppState;
+use crate::system_info::build_system_info;
+
+/// Leading byte on every datagram so mismatched binaries can tell a payload
+/// apart from a format they don't understand instead of panicking on decode.
+pub const GOSSIP_FORMAT_VERSION: u8 = 1;
+
+/// How many peers to push to per tick (epidemic/"push" gossip, not a full broadcast).
+const GOSSIP_FANOUT: usize = 3;
+const GOSSIP_TICK: Duration = Duration::from_secs(5);
+
+pub type NodeId = String;
+pub type ClusterMap = HashMap<NodeId, SystemInfo>;
+
+#[derive(Serialize, Deserialize)]
+struct GossipMessage {
+    node_id: NodeId,
+    instance_id: String,
+    seq: u64,
+    system_info: SystemInfo,
+}
+
+#[derive(Clone)]
+pub struct GossipConfig {
+    pub node_id: NodeId,
+    pub instance_id: String,
+    pub peers: Vec<SocketAddr>,
+}
+
+/// Periodically push this node's latest `SystemInfo` to a random subset of peers.
+pub async fn run_sender(
+    socket: Arc<UdpSocket>,
+    config: GossipConfig,
+    cpu_info: Arc<RwLock<CpuInfo>>,
+    system: Arc<RwLock<System>>,
+    shutdown: CancellationToken,
+) {
+    let mut seq: u64 = 0;
+
+    loop {
+        tokio::select! {
+            _ = sleep(GOSSIP_TICK) => {},
+            _ = shutdown.cancelled() => break,
+        }
+
+        if config.peers.is_empty() {
+            continue;
+        }
+
+        seq += 1;
+        let message = GossipMessage {
+            node_id: config.node_id.clone(),
+            instance_id: config.instance_id.clone(),
+            seq,
+            system_info: build_system_info(&cpu_info, &system).await,
+        };
+
+        let mut payload = match serde_json::to_vec(&message) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to encode gossip message");
+                continue;
+            }
+        };
+        let mut datagram = Vec::with_capacity(payload.len() + 1);
+        datagram.push(GOSSIP_FORMAT_VERSION);
+        datagram.append(&mut payload);
+
+        let mut rng = rand::thread_rng();
+        let targets: Vec<&SocketAddr> = config
+            .peers
+            .choose_multiple(&mut rng, GOSSIP_FANOUT.min(config.peers.len()))
+            .collect();
+
+        for peer in targets {
+            if let Err(err) = socket.send_to(&datagram, peer).await {
+                tracing::warn!(%peer, error = %err, "gossip send failed");
+            }
+        }
+    }
+
+    tracing::info!("gossip sender shut down");
+}
+
+/// Receive peer updates and merge them into the shared cluster map, ignoring
+/// stale updates for nodes we already have a higher sequence number for.
+///
+/// `seq` is only monotonic within a single process lifetime, so staleness is
+/// keyed on `(instance_id, seq)` rather than `seq` alone: a node that restarts
+/// gets a fresh `instance_id` and its counter resets to 1, which must be
+/// accepted immediately rather than compared against the pre-restart high
+/// water mark (that would otherwise blackout updates from a rebooted node).
+pub async fn run_receiver(
+    socket: Arc<UdpSocket>,
+    cluster: Arc<RwLock<ClusterMap>>,
+    shutdown: CancellationToken,
+) {
+    let mut last_seq: HashMap<NodeId, (String, u64)> = HashMap::new();
+    let mut buf = [0u8; 65536];
+
+    loop {
+        let (len, _addr) = tokio::select! {
+            result = socket.recv_from(&mut buf) => match result {
+                Ok(result) => result,
+                Err(err) => {
+                    tracing::warn!(error = %err, "gossip recv failed");
+                    continue;
+                }
+            },
+            _ = shutdown.cancelled() => break,
+        };
+
+        if len == 0 {
+            continue;
+        }
+
+        let version = buf[0];
+        if version != GOSSIP_FORMAT_VERSION {
+            tracing::warn!(version, "ignoring gossip datagram with unknown format version");
+            continue;
+        }
+
+        let message: GossipMessage = match serde_json::from_slice(&buf[1..len]) {
+            Ok(message) => message,
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to decode gossip message");
+                continue;
+            }
+        };
+
+        if let Some((seen_instance_id, seen_seq)) = last_seq.get(&message.node_id) {
+            if *seen_instance_id == message.instance_id && message.seq <= *seen_seq {
+                continue;
+            }
+        }
+        last_seq.insert(
+            message.node_id.clone(),
+            (message.instance_id.clone(), message.seq),
+        );
+
+        let mut cluster = cluster.write().await;
+        cluster.insert(message.node_id, message.system_info);
+    }
+
+    tracing::info!("gossip receiver shut down");
+}
+
+pub async fn get_cluster(data: web::Data<AppState>) -> impl Responder {
+    let cluster = data.cluster.read().await;
+    web::Json(cluster.clone())
+}
@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+
+use actix_web::{web, Responder};
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// Number of samples retained in the ring buffer (one per background tick).
+pub const DEFAULT_HISTORY_CAPACITY: usize = 120;
+
+pub type HistoryBuffer = VecDeque<Interval>;
+
+#[derive(Serialize, Clone)]
+pub struct NetworkDelta {
+    pub name: String,
+    pub received: u64,
+    pub transmitted: u64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct DiskSample {
+    pub name: String,
+    pub mount_point: String,
+    pub available_space: u64,
+    pub total_space: u64,
+}
+
+#[derive(Serialize, Clone)]
+pub struct Interval {
+    pub timestamp: i64,
+    pub instance_id: String,
+    pub cpu_usage: f32,
+    pub memory_used: u64,
+    pub memory_free: u64,
+    pub network: Vec<NetworkDelta>,
+    pub disks: Vec<DiskSample>,
+}
+
+/// Push `sample` onto `buffer`, evicting the oldest entry first if at capacity.
+pub fn push_sample(buffer: &mut HistoryBuffer, capacity: usize, sample: Interval) {
+    if buffer.len() >= capacity {
+        buffer.pop_front();
+    }
+    buffer.push_back(sample);
+}
+
+#[derive(Deserialize)]
+pub struct HistoryQuery {
+    since: Option<i64>,
+}
+
+pub async fn get_history(
+    data: web::Data<AppState>,
+    query: web::Query<HistoryQuery>,
+) -> impl Responder {
+    let history = data.history.read().await;
+
+    let samples: Vec<&Interval> = match query.since {
+        Some(since) => history.iter().filter(|s| s.timestamp >= since).collect(),
+        None => history.iter().collect(),
+    };
+
+    web::Json(samples.into_iter().cloned().collect::<Vec<_>>())
+}
@@ -0,0 +1,52 @@
+use actix_web::{web, Responder};
+use chrono::Utc;
+use serde::Serialize;
+use ulid::Ulid;
+
+use crate::state::AppState;
+
+#[cfg(target_os = "linux")]
+fn read_machine_id() -> Option<String> {
+    for path in ["/etc/machine-id", "/var/lib/dbus/machine-id"] {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            let trimmed = contents.trim();
+            if !trimmed.is_empty() {
+                return Some(trimmed.to_string());
+            }
+        }
+    }
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_machine_id() -> Option<String> {
+    None
+}
+
+/// Identity and boot metadata for this process, computed once at startup.
+///
+/// `instance_id` is a fresh ULID every restart, while `machine_id` is stable
+/// across restarts of the same host. Together they let a dashboard tell "the
+/// box rebooted" apart from "the box is unreachable" even when clocks drift.
+#[derive(Serialize, Clone)]
+pub struct Startup {
+    pub instance_id: String,
+    pub machine_id: Option<String>,
+    pub started_at: String,
+    pub version: String,
+}
+
+impl Startup {
+    pub fn capture() -> Self {
+        Startup {
+            instance_id: Ulid::new().to_string(),
+            machine_id: read_machine_id(),
+            started_at: Utc::now().to_rfc3339(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+pub async fn get_instance(data: web::Data<AppState>) -> impl Responder {
+    web::Json(data.startup.as_ref().clone())
+}
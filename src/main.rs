@@ -1,109 +1,51 @@
-use actix_web::{web, App, HttpServer, Responder};
-use serde::Serialize;
-use std::sync::Arc;
-use sysinfo::{Cpu, CpuRefreshKind, Disks, NetworkData, Networks, RefreshKind, System};
-use tokio::sync::RwLock;
-
-#[derive(Serialize, Clone)]
-struct MemoryInfo {
-    total: u64,
-    used: u64,
-    free: u64,
-}
-
-#[derive(Serialize, Clone)]
-struct DiskInfo {
-    name: String,
-    total_space: u64,
-    available_space: u64,
-}
-
-#[derive(Serialize, Clone)]
-struct NetworkStats {
-    name: String,
-    received: u64,
-    transmitted: u64,
-}
-
-#[derive(Serialize, Clone)]
-struct CpuInfo {
-    model: String,
-    usage: f32,
-}
-
-#[derive(Serialize, Clone)]
-struct SystemInfo {
-    cpu_info: CpuInfo,
-    num_cores: usize,
-    uptime: String,
-    hostname: String,
-    memory: MemoryInfo,
-    disks: Vec<DiskInfo>,
-    network: Vec<NetworkStats>,
-}
-
-#[derive(Clone)]
-struct AppState {
-    cpu_info: Arc<RwLock<CpuInfo>>,
-    system: Arc<RwLock<System>>,
-}
-
-fn format_uptime(seconds: u64) -> String {
-    let days = seconds / 86400;
-    let hours = (seconds % 86400) / 3600;
-    let minutes = (seconds % 3600) / 60;
-    let seconds = seconds % 60;
-
-    format!("{:02}:{:02}:{:02}:{:02}", days, hours, minutes, seconds)
-}
-
-async fn get_system_info(data: web::Data<AppState>) -> impl Responder {
-    let cpu_info = data.cpu_info.read().await;
-    let system = data.system.read().await;
-
-    let hostname = System::host_name().unwrap_or_else(|| "Unknown".to_string());
-    let uptime = format_uptime(System::uptime());
-    let num_cores = system.cpus().len();
-
-    let memory = MemoryInfo {
-        total: system.total_memory(),
-        used: system.total_memory() - system.available_memory(),
-        free: system.available_memory(),
-    };
+mod gossip;
+mod history;
+mod instance;
+mod metrics;
+mod models;
+mod processes;
+mod state;
+mod system_info;
+mod workers;
 
-    let disks = Disks::new_with_refreshed_list()
-        .iter()
-        .map(|d| DiskInfo {
-            name: d.name().to_string_lossy().to_string(),
-            total_space: d.total_space(),
-            available_space: d.available_space(),
-        })
-        .collect();
-
-    let network = Networks::new_with_refreshed_list()
-        .iter()
-        .map(|(name, data)| NetworkStats {
-            name: name.clone(),
-            received: data.received(),
-            transmitted: data.transmitted(),
-        })
-        .collect();
-
-    let info = SystemInfo {
-        cpu_info: cpu_info.clone(),
-        num_cores,
-        uptime,
-        hostname,
-        memory,
-        disks,
-        network,
-    };
+use std::sync::Arc;
+use std::time::Duration;
 
-    web::Json(info)
+use actix_web::{web, App, HttpServer};
+use sysinfo::{RefreshKind, System};
+use tokio::net::UdpSocket;
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::RwLock;
+use tracing_subscriber::EnvFilter;
+
+use gossip::GossipConfig;
+use instance::Startup;
+use models::CpuInfo;
+use state::AppState;
+use system_info::get_system_info;
+use workers::{CpuSamplerWorker, MemoryDiskSamplerWorker};
+
+const WORKER_TICK: Duration = Duration::from_secs(5);
+
+/// Reads a comma-separated `ip:port` list from `GOSSIP_PEERS`, e.g.
+/// `GOSSIP_PEERS=10.0.0.2:7879,10.0.0.3:7879`. Host names are not resolved:
+/// entries that do not parse as a socket address are skipped.
+fn gossip_peers_from_env() -> Vec<std::net::SocketAddr> {
+    std::env::var("GOSSIP_PEERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter_map(|s| s.parse().ok())
+        .collect()
 }
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .init();
+
     let mut sys = System::new_with_specifics(RefreshKind::everything());
     sys.refresh_all();
 
@@ -115,38 +57,109 @@ async fn main() -> std::io::Result<()> {
         usage: 0.0,
     }));
     let system = Arc::new(RwLock::new(sys));
+    let startup = Arc::new(Startup::capture());
+
+    let app_state = AppState::new(cpu_info.clone(), system.clone(), startup.clone());
+
+    {
+        let mut controls = app_state.worker_controls.write().await;
+        controls.insert(
+            "cpu-sampler".to_string(),
+            workers::spawn_worker(
+                CpuSamplerWorker::new(cpu_info.clone()),
+                WORKER_TICK,
+                app_state.workers.clone(),
+                app_state.shutdown.clone(),
+            ),
+        );
+        controls.insert(
+            "memory-disk-sampler".to_string(),
+            workers::spawn_worker(
+                MemoryDiskSamplerWorker::new(
+                    cpu_info.clone(),
+                    system.clone(),
+                    app_state.history.clone(),
+                    app_state.history_capacity,
+                    startup.instance_id.clone(),
+                ),
+                WORKER_TICK,
+                app_state.workers.clone(),
+                app_state.shutdown.clone(),
+            ),
+        );
+    }
+
+    let gossip_bind_addr =
+        std::env::var("GOSSIP_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:7879".to_string());
+    let gossip_socket = Arc::new(UdpSocket::bind(&gossip_bind_addr).await?);
+    let gossip_config = GossipConfig {
+        node_id: System::host_name().unwrap_or_else(|| "unknown-node".to_string()),
+        instance_id: startup.instance_id.clone(),
+        peers: gossip_peers_from_env(),
+    };
 
-    let cpu_info_clone = cpu_info.clone();
+    let gossip_send_socket = gossip_socket.clone();
+    let gossip_send_config = gossip_config.clone();
+    let gossip_cpu_info = cpu_info.clone();
+    let gossip_system = system.clone();
+    let gossip_send_shutdown = app_state.shutdown.clone();
+    tokio::spawn(async move {
+        gossip::run_sender(
+            gossip_send_socket,
+            gossip_send_config,
+            gossip_cpu_info,
+            gossip_system,
+            gossip_send_shutdown,
+        )
+        .await;
+    });
 
+    let gossip_cluster = app_state.cluster.clone();
+    let gossip_recv_shutdown = app_state.shutdown.clone();
     tokio::spawn(async move {
-        let mut sys = System::new_with_specifics(
-            RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()));
-        loop {
-            // Wait a bit because CPU usage is based on time interval.
-            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-            sys.refresh_cpu_all();
-            let mut total_usage = 0.0;
-            for cpu in sys.cpus() {
-                let cpu_usage = cpu.cpu_usage();
-                total_usage += cpu_usage;
-                println!("{}%", cpu.cpu_usage());
-            }
-
-            println!("[DEBUG] Total CPU usage: {}", total_usage);
-
-            let mut cpu_data = cpu_info_clone.write().await;
-            cpu_data.usage = total_usage;
-        }
+        gossip::run_receiver(gossip_socket, gossip_cluster, gossip_recv_shutdown).await;
     });
 
-    let app_state = AppState { cpu_info, system };
+    let shutdown = app_state.shutdown.clone();
 
-    HttpServer::new(move || {
+    let server = HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(app_state.clone()))
             .route("/api/system-info", web::get().to(get_system_info))
+            .route("/api/history", web::get().to(history::get_history))
+            .route("/api/cluster", web::get().to(gossip::get_cluster))
+            .route("/api/processes", web::get().to(processes::get_processes))
+            .route("/api/workers", web::get().to(workers::list_workers))
+            .route(
+                "/api/workers/{name}/control",
+                web::post().to(workers::control_worker),
+            )
+            .route("/metrics", web::get().to(metrics::get_metrics))
+            .route("/api/instance", web::get().to(instance::get_instance))
     })
     .bind("0.0.0.0:8080")?
-    .run()
-    .await
+    .run();
+
+    tokio::spawn(shutdown_signal_task(server.handle(), shutdown));
+
+    server.await
+}
+
+/// Waits for SIGINT/SIGTERM, then cancels the background workers/gossip
+/// loops and asks the HTTP server to drain in-flight requests before exiting.
+async fn shutdown_signal_task(
+    server_handle: actix_web::dev::ServerHandle,
+    shutdown: tokio_util::sync::CancellationToken,
+) {
+    let mut sigterm =
+        signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = sigterm.recv() => {}
+    }
+
+    tracing::info!("shutdown signal received, draining in-flight requests");
+    shutdown.cancel();
+    server_handle.stop(true).await;
 }
@@ -0,0 +1,93 @@
+use std::fmt::Write as _;
+
+use actix_web::{web, HttpResponse, Responder};
+use sysinfo::{Disks, Networks, System};
+
+use crate::state::AppState;
+
+/// Escapes a Prometheus label value per the text exposition format.
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Renders the same data `SystemInfo` exposes as Prometheus text exposition
+/// format, for scraping by an external Prometheus server.
+pub async fn get_metrics(data: web::Data<AppState>) -> impl Responder {
+    let cpu_info = data.cpu_info.read().await;
+    let system = data.system.read().await;
+
+    let mut body = String::new();
+
+    // cpu_info.usage is the sum of per-core cpu_usage() (see CpuSamplerWorker),
+    // so it must be averaged back down to a single 0-100 scale here.
+    let num_cores = system.cpus().len().max(1) as f32;
+    body.push_str("# HELP node_cpu_usage_percent Average CPU usage across all cores, in percent.\n");
+    body.push_str("# TYPE node_cpu_usage_percent gauge\n");
+    let _ = writeln!(body, "node_cpu_usage_percent {}", cpu_info.usage / num_cores);
+
+    body.push_str("# HELP node_memory_used_bytes Memory currently in use, in bytes.\n");
+    body.push_str("# TYPE node_memory_used_bytes gauge\n");
+    let _ = writeln!(
+        body,
+        "node_memory_used_bytes {}",
+        system.total_memory() - system.available_memory()
+    );
+
+    body.push_str("# HELP node_memory_total_bytes Total installed memory, in bytes.\n");
+    body.push_str("# TYPE node_memory_total_bytes gauge\n");
+    let _ = writeln!(body, "node_memory_total_bytes {}", system.total_memory());
+
+    // `disk.name()` is not unique (every tmpfs mount reports "tmpfs"), and
+    // duplicate metric name + label sets make Prometheus reject the whole
+    // scrape, so the mount point is included to keep each series unique.
+    body.push_str("# HELP node_disk_available_bytes Available space per disk, in bytes.\n");
+    body.push_str("# TYPE node_disk_available_bytes gauge\n");
+    for disk in Disks::new_with_refreshed_list().iter() {
+        let device = escape_label_value(&disk.name().to_string_lossy());
+        let mountpoint = escape_label_value(&disk.mount_point().to_string_lossy());
+        let _ = writeln!(
+            body,
+            "node_disk_available_bytes{{device=\"{device}\",mountpoint=\"{mountpoint}\"}} {}",
+            disk.available_space()
+        );
+    }
+
+    let networks = Networks::new_with_refreshed_list();
+
+    body.push_str(
+        "# HELP node_network_receive_bytes_total Cumulative bytes received per interface.\n",
+    );
+    body.push_str("# TYPE node_network_receive_bytes_total counter\n");
+    for (name, net) in networks.iter() {
+        let device = escape_label_value(name);
+        let _ = writeln!(
+            body,
+            "node_network_receive_bytes_total{{device=\"{device}\"}} {}",
+            net.received()
+        );
+    }
+
+    body.push_str(
+        "# HELP node_network_transmit_bytes_total Cumulative bytes transmitted per interface.\n",
+    );
+    body.push_str("# TYPE node_network_transmit_bytes_total counter\n");
+    for (name, net) in networks.iter() {
+        let device = escape_label_value(name);
+        let _ = writeln!(
+            body,
+            "node_network_transmit_bytes_total{{device=\"{device}\"}} {}",
+            net.transmitted()
+        );
+    }
+
+    body.push_str("# HELP node_uptime_seconds System uptime, in seconds.\n");
+    body.push_str("# TYPE node_uptime_seconds counter\n");
+    let _ = writeln!(body, "node_uptime_seconds {}", System::uptime());
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(body)
+}
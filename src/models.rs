@@ -0,0 +1,59 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MemoryInfo {
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DiskInfo {
+    pub name: String,
+    pub total_space: u64,
+    pub available_space: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct NetworkStats {
+    pub name: String,
+    pub received: u64,
+    pub transmitted: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CpuInfo {
+    pub model: String,
+    pub usage: f32,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SystemInfo {
+    pub cpu_info: CpuInfo,
+    pub num_cores: usize,
+    pub uptime: String,
+    pub hostname: String,
+    pub memory: MemoryInfo,
+    pub disks: Vec<DiskInfo>,
+    pub network: Vec<NetworkStats>,
+}
+
+/// Current unix timestamp in seconds, used to stamp history samples and
+/// worker status reports.
+pub fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+pub fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let hours = (seconds % 86400) / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let seconds = seconds % 60;
+
+    format!("{:02}:{:02}:{:02}:{:02}", days, hours, minutes, seconds)
+}
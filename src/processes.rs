@@ -0,0 +1,76 @@
+use std::cmp::Reverse;
+
+use actix_web::{web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System};
+use tokio::time::sleep;
+
+const DEFAULT_PROCESS_LIMIT: usize = 10;
+
+#[derive(Serialize, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory: u64,
+    pub disk_read_bytes: u64,
+    pub disk_write_bytes: u64,
+    pub run_time: u64,
+}
+
+#[derive(Deserialize)]
+pub struct ProcessQuery {
+    sort: Option<String>,
+    limit: Option<usize>,
+}
+
+/// Top-N processes by CPU or memory usage. Deliberately not part of the
+/// `/api/system-info` snapshot: enumerating every process is too expensive to
+/// pay on every poll of the cheap hot path.
+pub async fn get_processes(query: web::Query<ProcessQuery>) -> impl Responder {
+    // Validate before paying for the (slow, two-refresh) process enumeration
+    // below, so an unrecognized sort value fails fast instead of silently
+    // falling back to cpu sort on a typo like `?sort=memory`.
+    match query.sort.as_deref() {
+        None | Some("cpu") | Some("mem") => {}
+        Some(other) => {
+            return HttpResponse::BadRequest().body(format!("unknown sort: {other}"));
+        }
+    }
+
+    let mut system = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+    );
+
+    // Like CpuSamplerWorker::step, per-process cpu_usage() is a delta between
+    // two refreshes; a single snapshot would report 0.0 for every process.
+    sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut processes: Vec<ProcessInfo> = system
+        .processes()
+        .values()
+        .map(|process| {
+            let disk_usage = process.disk_usage();
+            ProcessInfo {
+                pid: process.pid().as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                disk_read_bytes: disk_usage.total_read_bytes,
+                disk_write_bytes: disk_usage.total_written_bytes,
+                run_time: process.run_time(),
+            }
+        })
+        .collect();
+
+    match query.sort.as_deref() {
+        Some("mem") => processes.sort_by_key(|p| Reverse(p.memory)),
+        _ => processes.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage)),
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_PROCESS_LIMIT);
+    processes.truncate(limit);
+
+    HttpResponse::Ok().json(processes)
+}
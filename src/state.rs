@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use sysinfo::System;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::gossip::ClusterMap;
+use crate::history::{HistoryBuffer, DEFAULT_HISTORY_CAPACITY};
+use crate::instance::Startup;
+use crate::models::CpuInfo;
+use crate::workers::{WorkerControls, WorkerRegistry};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub cpu_info: Arc<RwLock<CpuInfo>>,
+    pub system: Arc<RwLock<System>>,
+    pub history: Arc<RwLock<HistoryBuffer>>,
+    pub history_capacity: usize,
+    pub cluster: Arc<RwLock<ClusterMap>>,
+    pub workers: WorkerRegistry,
+    pub worker_controls: WorkerControls,
+    pub shutdown: CancellationToken,
+    pub startup: Arc<Startup>,
+}
+
+impl AppState {
+    pub fn new(cpu_info: Arc<RwLock<CpuInfo>>, system: Arc<RwLock<System>>, startup: Arc<Startup>) -> Self {
+        AppState {
+            cpu_info,
+            system,
+            history: Arc::new(RwLock::new(HistoryBuffer::new())),
+            history_capacity: DEFAULT_HISTORY_CAPACITY,
+            cluster: Arc::new(RwLock::new(ClusterMap::new())),
+            workers: Arc::new(RwLock::new(HashMap::new())),
+            worker_controls: Arc::new(RwLock::new(HashMap::new())),
+            shutdown: CancellationToken::new(),
+            startup,
+        }
+    }
+}
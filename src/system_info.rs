@@ -0,0 +1,58 @@
+use actix_web::{web, Responder};
+use sysinfo::{Disks, Networks, System};
+use tokio::sync::RwLock;
+
+use crate::models::{format_uptime, CpuInfo, DiskInfo, MemoryInfo, NetworkStats, SystemInfo};
+use crate::state::AppState;
+
+/// Assemble a fresh `SystemInfo` snapshot from the shared CPU/system state.
+///
+/// Used both by the `/api/system-info` handler and by the gossip sender, which
+/// needs the same snapshot to propagate to peers.
+pub async fn build_system_info(cpu_info: &RwLock<CpuInfo>, system: &RwLock<System>) -> SystemInfo {
+    let cpu_info = cpu_info.read().await;
+    let system = system.read().await;
+
+    let hostname = System::host_name().unwrap_or_else(|| "Unknown".to_string());
+    let uptime = format_uptime(System::uptime());
+    let num_cores = system.cpus().len();
+
+    let memory = MemoryInfo {
+        total: system.total_memory(),
+        used: system.total_memory() - system.available_memory(),
+        free: system.available_memory(),
+    };
+
+    let disks = Disks::new_with_refreshed_list()
+        .iter()
+        .map(|d| DiskInfo {
+            name: d.name().to_string_lossy().to_string(),
+            total_space: d.total_space(),
+            available_space: d.available_space(),
+        })
+        .collect();
+
+    let network = Networks::new_with_refreshed_list()
+        .iter()
+        .map(|(name, data)| NetworkStats {
+            name: name.clone(),
+            received: data.received(),
+            transmitted: data.transmitted(),
+        })
+        .collect();
+
+    SystemInfo {
+        cpu_info: cpu_info.clone(),
+        num_cores,
+        uptime,
+        hostname,
+        memory,
+        disks,
+        network,
+    }
+}
+
+pub async fn get_system_info(data: web::Data<AppState>) -> impl Responder {
+    let info = build_system_info(&data.cpu_info, &data.system).await;
+    web::Json(info)
+}
@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::{web, HttpResponse, Responder};
+use async_trait::async_trait;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use sysinfo::{CpuRefreshKind, Disks, Networks, RefreshKind, System};
+use tokio::sync::{watch, RwLock};
+use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
+
+use crate::history::{push_sample, DiskSample, HistoryBuffer, Interval, NetworkDelta};
+use crate::models::{unix_timestamp, CpuInfo};
+use crate::state::AppState;
+
+#[async_trait]
+pub trait Worker: Send + Sync {
+    fn name(&self) -> &str;
+    async fn step(&mut self) -> Result<(), String>;
+}
+
+#[derive(Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerState {
+    Active,
+    Idle,
+    /// Last step failed or panicked, but the worker keeps retrying on the
+    /// next tick — distinct from a worker that has actually stopped running.
+    Errored,
+}
+
+#[derive(Serialize, Clone)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerState,
+    pub last_run: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+pub type WorkerRegistry = Arc<RwLock<HashMap<String, WorkerStatus>>>;
+pub type WorkerControls = Arc<RwLock<HashMap<String, watch::Sender<bool>>>>;
+
+async fn set_status(registry: &WorkerRegistry, name: &str, state: WorkerState, error: Option<String>) {
+    let mut registry = registry.write().await;
+    let entry = registry
+        .entry(name.to_string())
+        .or_insert_with(|| WorkerStatus {
+            name: name.to_string(),
+            state: WorkerState::Idle,
+            last_run: None,
+            last_error: None,
+        });
+    entry.state = state;
+    entry.last_run = Some(unix_timestamp());
+    if error.is_some() {
+        entry.last_error = error;
+    }
+}
+
+/// Like `set_status`, but for transitions that aren't a step actually
+/// running (e.g. going idle because the worker is paused) — `last_run`
+/// stays untouched so it keeps reflecting the last real tick.
+async fn set_state(registry: &WorkerRegistry, name: &str, state: WorkerState) {
+    let mut registry = registry.write().await;
+    let entry = registry
+        .entry(name.to_string())
+        .or_insert_with(|| WorkerStatus {
+            name: name.to_string(),
+            state: WorkerState::Idle,
+            last_run: None,
+            last_error: None,
+        });
+    entry.state = state;
+}
+
+/// Spawn `worker` in its own task, ticking every `interval`. Reports Active /
+/// Idle / Errored (with the last error, including recovered panics) into
+/// `registry`, returns a control handle the `/api/workers` route uses to
+/// pause/resume it without killing the task, and exits cleanly once
+/// `shutdown` is cancelled.
+pub fn spawn_worker<W>(
+    mut worker: W,
+    interval: Duration,
+    registry: WorkerRegistry,
+    shutdown: CancellationToken,
+) -> watch::Sender<bool>
+where
+    W: Worker + 'static,
+{
+    let name = worker.name().to_string();
+    let (pause_tx, mut pause_rx) = watch::channel(false);
+
+    tokio::spawn(async move {
+        registry.write().await.insert(
+            name.clone(),
+            WorkerStatus {
+                name: name.clone(),
+                state: WorkerState::Idle,
+                last_run: None,
+                last_error: None,
+            },
+        );
+
+        loop {
+            if *pause_rx.borrow() {
+                set_state(&registry, &name, WorkerState::Idle).await;
+                tokio::select! {
+                    _ = pause_rx.changed() => continue,
+                    _ = shutdown.cancelled() => break,
+                }
+            }
+
+            let outcome = std::panic::AssertUnwindSafe(worker.step()).catch_unwind().await;
+
+            match outcome {
+                Ok(Ok(())) => set_status(&registry, &name, WorkerState::Active, None).await,
+                Ok(Err(err)) => {
+                    tracing::warn!(worker = %name, error = %err, "worker step failed");
+                    set_status(&registry, &name, WorkerState::Errored, Some(err)).await
+                }
+                Err(panic) => {
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_else(|| "worker panicked".to_string());
+                    tracing::error!(worker = %name, error = %message, "worker panicked");
+                    set_status(&registry, &name, WorkerState::Errored, Some(message)).await;
+                }
+            }
+
+            tokio::select! {
+                _ = sleep(interval) => {},
+                _ = shutdown.cancelled() => break,
+            }
+        }
+
+        tracing::info!(worker = %name, "worker shut down");
+    });
+
+    pause_tx
+}
+
+#[derive(Deserialize)]
+pub struct ControlRequest {
+    pub action: String,
+}
+
+pub async fn list_workers(data: web::Data<AppState>) -> impl Responder {
+    let registry = data.workers.read().await;
+    web::Json(registry.values().cloned().collect::<Vec<_>>())
+}
+
+pub async fn control_worker(
+    data: web::Data<AppState>,
+    path: web::Path<String>,
+    body: web::Json<ControlRequest>,
+) -> impl Responder {
+    let name = path.into_inner();
+    let controls = data.worker_controls.read().await;
+
+    let Some(sender) = controls.get(&name) else {
+        return HttpResponse::NotFound().body(format!("unknown worker: {name}"));
+    };
+
+    match body.action.as_str() {
+        "pause" => {
+            let _ = sender.send(true);
+            HttpResponse::Ok().finish()
+        }
+        "resume" => {
+            let _ = sender.send(false);
+            HttpResponse::Ok().finish()
+        }
+        other => HttpResponse::BadRequest().body(format!("unknown action: {other}")),
+    }
+}
+
+/// First worker: the CPU sampling loop that used to be a hand-rolled
+/// `tokio::spawn`, now reporting into the worker registry like any other.
+pub struct CpuSamplerWorker {
+    cpu_info: Arc<RwLock<CpuInfo>>,
+    system: System,
+}
+
+impl CpuSamplerWorker {
+    pub fn new(cpu_info: Arc<RwLock<CpuInfo>>) -> Self {
+        CpuSamplerWorker {
+            cpu_info,
+            system: System::new_with_specifics(
+                RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl Worker for CpuSamplerWorker {
+    fn name(&self) -> &str {
+        "cpu-sampler"
+    }
+
+    async fn step(&mut self) -> Result<(), String> {
+        // sysinfo needs a minimum interval between refreshes for an accurate reading.
+        sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
+        self.system.refresh_cpu_all();
+
+        let total_usage: f32 = self.system.cpus().iter().map(|cpu| cpu.cpu_usage()).sum();
+        tracing::debug!(cpu_usage = total_usage, "sampled cpu usage");
+
+        let mut cpu_info = self.cpu_info.write().await;
+        cpu_info.usage = total_usage;
+        Ok(())
+    }
+}
+
+/// Second worker: samples memory, disks, and network (the CPU usage it
+/// stamps onto each `Interval` comes from `CpuSamplerWorker`'s shared state)
+/// into the history ring buffer.
+pub struct MemoryDiskSamplerWorker {
+    cpu_info: Arc<RwLock<CpuInfo>>,
+    system: Arc<RwLock<System>>,
+    history: Arc<RwLock<HistoryBuffer>>,
+    history_capacity: usize,
+    instance_id: String,
+    prev_network: HashMap<String, (u64, u64)>,
+}
+
+impl MemoryDiskSamplerWorker {
+    pub fn new(
+        cpu_info: Arc<RwLock<CpuInfo>>,
+        system: Arc<RwLock<System>>,
+        history: Arc<RwLock<HistoryBuffer>>,
+        history_capacity: usize,
+        instance_id: String,
+    ) -> Self {
+        MemoryDiskSamplerWorker {
+            cpu_info,
+            system,
+            history,
+            history_capacity,
+            instance_id,
+            prev_network: HashMap::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Worker for MemoryDiskSamplerWorker {
+    fn name(&self) -> &str {
+        "memory-disk-sampler"
+    }
+
+    async fn step(&mut self) -> Result<(), String> {
+        let (memory_used, memory_free) = {
+            let mut system = self.system.write().await;
+            system.refresh_memory();
+            (
+                system.total_memory() - system.available_memory(),
+                system.available_memory(),
+            )
+        };
+
+        // Network counters from sysinfo are cumulative, so diff against the
+        // previous tick's reading (clamped at 0) to get a per-interval delta.
+        // On an interface's first observation there's no prior reading to
+        // diff against, so seed it with the current cumulative value instead
+        // of 0 — otherwise the first sample would report the full
+        // since-boot counter as a bogus one-tick spike.
+        let networks = Networks::new_with_refreshed_list();
+        let network = networks
+            .iter()
+            .map(|(name, data)| {
+                let received = data.received();
+                let transmitted = data.transmitted();
+                let (prev_received, prev_transmitted) = self
+                    .prev_network
+                    .get(name)
+                    .copied()
+                    .unwrap_or((received, transmitted));
+                self.prev_network.insert(name.clone(), (received, transmitted));
+
+                NetworkDelta {
+                    name: name.clone(),
+                    received: received.saturating_sub(prev_received),
+                    transmitted: transmitted.saturating_sub(prev_transmitted),
+                }
+            })
+            .collect();
+
+        // Disk space isn't a cumulative counter like the network byte
+        // totals, so each tick just reports the current snapshot rather
+        // than a delta against the previous sample.
+        let disks = Disks::new_with_refreshed_list()
+            .iter()
+            .map(|disk| DiskSample {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                available_space: disk.available_space(),
+                total_space: disk.total_space(),
+            })
+            .collect();
+
+        let cpu_usage = self.cpu_info.read().await.usage;
+
+        let sample = Interval {
+            timestamp: unix_timestamp(),
+            instance_id: self.instance_id.clone(),
+            cpu_usage,
+            memory_used,
+            memory_free,
+            network,
+            disks,
+        };
+
+        let mut history = self.history.write().await;
+        push_sample(&mut history, self.history_capacity, sample);
+        Ok(())
+    }
+}